@@ -46,30 +46,8 @@
 //!     ],
 //!     "kind": "float",
 //!     "data": [
-//!       182,
-//!       59,
-//!       207,
-//!       190,
-//!       12,
-//!       195,
-//!       95,
-//!       62,
-//!       123,
-//!       68,
-//!       200,
-//!       191,
-//!       242,
-//!       98,
-//!       231,
-//!       190,
-//!       108,
-//!       94,
-//!       225,
-//!       62,
-//!       56,
-//!       45,
-//!       3,
-//!       190
+//!       [-0.04130, 0.97336, 1.23456],
+//!       [-0.58261, 0.11206, -0.70345]
 //!     ]
 //!   },
 //!   "kind": "float",
@@ -77,13 +55,17 @@
 //!   "reduction": "mean",
 //! }
 //! ```
+//!
+//! For human-readable formats like JSON, `data` is shaped to match `shape` and holds
+//! typed scalars, as shown above. Binary formats such as bincode instead get a flat,
+//! little-endian byte buffer (see [`TensorRepr`]).
 
 use half::f16;
 use serde::{
-    de::Error as DeserializeError, ser::Error as SerializeError, Deserialize, Deserializer,
-    Serialize, Serializer,
+    de::Error as DeserializeError, ser::Error as SerializeError, ser::SerializeSeq, Deserialize,
+    Deserializer, Serialize, Serializer,
 };
-use std::{borrow::Cow, mem};
+use std::{borrow::Cow, cell::Cell, mem};
 use tch::{Device, Kind, Reduction, Tensor};
 
 /// The serialized representation of [Tensor].
@@ -97,13 +79,464 @@ pub struct TensorRepr {
     pub shape: Vec<i64>,
     #[serde(with = "serde_kind")]
     pub kind: Kind,
+    pub endianness: Endianness,
+    #[serde(with = "serde_bytes")]
     pub data: Vec<u8>,
 }
 
+/// Byte order in which [`TensorRepr::data`] is encoded on the wire.
+///
+/// `data` is always normalized to [`Endianness::Little`] during serialization, regardless
+/// of the host's native byte order, so that a payload produced on one platform decodes
+/// correctly on another. The field doubles as a format marker: a reader that encounters
+/// a variant it doesn't recognize should reject the payload rather than guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Endianness {
+    #[serde(rename = "little")]
+    Little,
+}
+
+/// Returns the byte size of a single element of `kind`. `Complex*` kinds are an
+/// interleaved real/imaginary pair, so their element size is twice that of the
+/// corresponding real kind.
+fn elem_size(kind: Kind) -> usize {
+    use Kind::*;
+    match kind {
+        Uint8 => mem::size_of::<u8>(),
+        Int8 => mem::size_of::<i8>(),
+        Int16 => mem::size_of::<i16>(),
+        Int => mem::size_of::<i32>(),
+        Int64 => mem::size_of::<i64>(),
+        Half => mem::size_of::<f16>(),
+        Float => mem::size_of::<f32>(),
+        Double => mem::size_of::<f64>(),
+        ComplexHalf => 2 * mem::size_of::<f16>(),
+        ComplexFloat => 2 * mem::size_of::<f32>(),
+        ComplexDouble => 2 * mem::size_of::<f64>(),
+        Bool => mem::size_of::<bool>(),
+        QInt8 => mem::size_of::<i8>(),
+        QUInt8 => mem::size_of::<u8>(),
+        QInt32 => mem::size_of::<i32>(),
+        BFloat16 => mem::size_of::<f16>(),
+    }
+}
+
+/// Returns the byte size of the sub-unit that a `kind`'s element should be byte-swapped
+/// in. Equal to [`elem_size`] except for `Complex*` kinds, whose real and imaginary
+/// halves are each swapped independently rather than reversing the whole element.
+fn swap_unit_size(kind: Kind) -> usize {
+    use Kind::*;
+    match kind {
+        ComplexHalf => mem::size_of::<f16>(),
+        ComplexFloat => mem::size_of::<f32>(),
+        ComplexDouble => mem::size_of::<f64>(),
+        other => elem_size(other),
+    }
+}
+
+/// Computes the number of elements `shape` describes, rejecting negative dimensions and
+/// guarding the multiplication against overflow.
+fn checked_numel(shape: &[i64]) -> Result<usize, String> {
+    shape.iter().try_fold(1usize, |acc, &dim| {
+        let dim = usize::try_from(dim)
+            .map_err(|_| format!("tensor shape has a negative dimension: {}", dim))?;
+        acc.checked_mul(dim)
+            .ok_or_else(|| "tensor shape overflows when computing its element count".to_string())
+    })
+}
+
+/// Computes the expected byte length of `shape`'s worth of `kind`-typed elements,
+/// rejecting negative dimensions and overflow.
+fn expected_byte_len(shape: &[i64], kind: Kind) -> Result<usize, String> {
+    let numel = checked_numel(shape)?;
+    numel
+        .checked_mul(elem_size(kind))
+        .ok_or_else(|| "tensor shape overflows when computing its byte length".to_string())
+}
+
+/// Configurable limits for [`serde_tensor::deserialize_with_limit`] and
+/// [`serde_tensor_base64::deserialize_with_limit`], to reject a tensor whose element or
+/// byte count is too large before `Tensor::of_data_size` ever runs. The limits are
+/// checked after the payload is parsed, so they do not bound the parsing itself.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeserializeOptions {
+    /// Maximum number of elements the tensor may contain. `None` means unbounded.
+    pub max_elements: Option<usize>,
+    /// Maximum number of bytes the tensor's data may occupy (element count times
+    /// element size), not the size of the encoded payload. `None` means unbounded.
+    pub max_bytes: Option<usize>,
+}
+
+/// Checks `shape`/`kind` against `max_elements`/`max_bytes` (each `None` meaning
+/// unbounded). A negative dimension or an overflowing element count is rejected even
+/// when both limits are `None`, since [`checked_numel`] always runs.
+/// Shared by [`serde_tensor::deserialize_with_limit`] and
+/// [`serde_tensor_base64::deserialize_with_limit`].
+fn check_limits(
+    shape: &[i64],
+    kind: Kind,
+    max_elements: Option<usize>,
+    max_bytes: Option<usize>,
+) -> Result<(), String> {
+    let numel = checked_numel(shape)?;
+
+    if let Some(max_elements) = max_elements {
+        if numel > max_elements {
+            return Err(format!(
+                "tensor has {} elements, exceeding the configured limit of {}",
+                numel, max_elements
+            ));
+        }
+    }
+
+    if let Some(max_bytes) = max_bytes {
+        let byte_len = expected_byte_len(shape, kind)?;
+        if byte_len > max_bytes {
+            return Err(format!(
+                "tensor would use {} bytes, exceeding the configured limit of {}",
+                byte_len, max_bytes
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Byte-swaps `buffer` in place, `unit_size`-sized chunk by chunk. A no-op for
+/// single-byte units (`unit_size == 1`).
+fn swap_endianness(buffer: &mut [u8], unit_size: usize) {
+    if unit_size <= 1 {
+        return;
+    }
+    for chunk in buffer.chunks_exact_mut(unit_size) {
+        chunk.reverse();
+    }
+}
+
+/// bfloat16 is the upper 16 bits of an `f32`; `half` doesn't expose a dedicated type for
+/// it, so we widen/narrow by hand.
+fn bf16_to_f32(bits: u16) -> f32 {
+    f32::from_bits((bits as u32) << 16)
+}
+
+fn f32_to_bf16(value: f32) -> u16 {
+    (value.to_bits() >> 16) as u16
+}
+
 /// Serializing/Deserializing functions for [Tensor].
+///
+/// When the target format is human-readable (`Serializer::is_human_readable`, e.g.
+/// JSON/YAML/TOML), `data` is emitted as nested arrays of typed scalars shaped like
+/// `shape`, instead of the flat raw-byte form used by binary formats such as bincode.
+///
+/// [`deserialize`] validates `shape`/`data` against `kind` before ever allocating a
+/// tensor; use [`deserialize_with_limit`] to additionally cap the tensor's element or
+/// byte count when deserializing payloads from an untrusted source.
 pub mod serde_tensor {
     use super::*;
 
+    /// Human-readable counterpart of [`TensorRepr`]: `data` is nested arrays of typed
+    /// scalars rather than a flat byte buffer, so there's no need for a wire-endianness
+    /// marker.
+    #[derive(Serialize)]
+    struct HumanReadableRepr<'a> {
+        requires_grad: bool,
+        #[serde(with = "serde_device")]
+        device: Device,
+        shape: Vec<i64>,
+        #[serde(with = "serde_kind")]
+        kind: Kind,
+        data: NestedData<'a>,
+    }
+
+    #[derive(Deserialize)]
+    struct HumanReadableReprOwned {
+        requires_grad: bool,
+        #[serde(with = "serde_device")]
+        device: Device,
+        shape: Vec<i64>,
+        #[serde(with = "serde_kind")]
+        kind: Kind,
+        data: NestedScalar,
+    }
+
+    /// Reshapes a flat, host-native-order byte buffer into nested sequences matching
+    /// `shape`, emitting one typed scalar per leaf.
+    struct NestedData<'a> {
+        bytes: &'a [u8],
+        shape: &'a [i64],
+        kind: Kind,
+        offset: &'a Cell<usize>,
+    }
+
+    impl<'a> Serialize for NestedData<'a> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self.shape.split_first() {
+                None => serialize_scalar(self.bytes, self.offset, self.kind, serializer),
+                Some((&dim, rest)) => {
+                    let len = usize::try_from(dim)
+                        .map_err(|_| S::Error::custom("tensor shape has a negative dimension"))?;
+                    let mut seq = serializer.serialize_seq(Some(len))?;
+                    for _ in 0..len {
+                        seq.serialize_element(&NestedData {
+                            bytes: self.bytes,
+                            shape: rest,
+                            kind: self.kind,
+                            offset: self.offset,
+                        })?;
+                    }
+                    seq.end()
+                }
+            }
+        }
+    }
+
+    /// Serializes `value` as a plain `f32` when finite; JSON (and similarly lenient
+    /// formats) otherwise maps `NaN`/`±Infinity` to `null`, which nothing can
+    /// deserialize back, so non-finite values fall back to a string sentinel
+    /// (`"NaN"`/`"inf"`/`"-inf"`) that [`as_f64`] parses back on deserialize.
+    fn serialize_f32_scalar<S>(value: f32, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if value.is_finite() {
+            value.serialize(serializer)
+        } else {
+            value.to_string().serialize(serializer)
+        }
+    }
+
+    /// `f64` counterpart of [`serialize_f32_scalar`].
+    fn serialize_f64_scalar<S>(value: f64, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if value.is_finite() {
+            value.serialize(serializer)
+        } else {
+            value.to_string().serialize(serializer)
+        }
+    }
+
+    /// Wraps an `f32` so it can be used as an array element (e.g. a complex `[re, im]`
+    /// pair) while still going through [`serialize_f32_scalar`]'s sentinel handling.
+    struct F32Scalar(f32);
+
+    impl Serialize for F32Scalar {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_f32_scalar(self.0, serializer)
+        }
+    }
+
+    /// `f64` counterpart of [`F32Scalar`].
+    struct F64Scalar(f64);
+
+    impl Serialize for F64Scalar {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_f64_scalar(self.0, serializer)
+        }
+    }
+
+    /// Reads one `kind`-typed scalar out of `bytes` at `offset` (advancing it) and
+    /// serializes it as a native scalar (`f32`, `i64`, `bool`, ...).
+    fn serialize_scalar<S>(
+        bytes: &[u8],
+        offset: &Cell<usize>,
+        kind: Kind,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use Kind::*;
+
+        let size = elem_size(kind);
+        let start = offset.get();
+        let end = start + size;
+        let chunk = bytes
+            .get(start..end)
+            .ok_or_else(|| S::Error::custom("tensor data buffer is shorter than shape requires"))?;
+        offset.set(end);
+
+        match kind {
+            Uint8 | QUInt8 => chunk[0].serialize(serializer),
+            Int8 | QInt8 => (chunk[0] as i8).serialize(serializer),
+            Int16 => i16::from_ne_bytes(chunk.try_into().unwrap()).serialize(serializer),
+            Int | QInt32 => i32::from_ne_bytes(chunk.try_into().unwrap()).serialize(serializer),
+            Int64 => i64::from_ne_bytes(chunk.try_into().unwrap()).serialize(serializer),
+            Half => serialize_f32_scalar(
+                f16::from_ne_bytes(chunk.try_into().unwrap()).to_f32(),
+                serializer,
+            ),
+            BFloat16 => serialize_f32_scalar(
+                bf16_to_f32(u16::from_ne_bytes(chunk.try_into().unwrap())),
+                serializer,
+            ),
+            Float => {
+                serialize_f32_scalar(f32::from_ne_bytes(chunk.try_into().unwrap()), serializer)
+            }
+            Double => {
+                serialize_f64_scalar(f64::from_ne_bytes(chunk.try_into().unwrap()), serializer)
+            }
+            Bool => (chunk[0] != 0).serialize(serializer),
+            ComplexHalf => {
+                let (re, im) = chunk.split_at(mem::size_of::<f16>());
+                [
+                    F32Scalar(f16::from_ne_bytes(re.try_into().unwrap()).to_f32()),
+                    F32Scalar(f16::from_ne_bytes(im.try_into().unwrap()).to_f32()),
+                ]
+                .serialize(serializer)
+            }
+            ComplexFloat => {
+                let (re, im) = chunk.split_at(mem::size_of::<f32>());
+                [
+                    F32Scalar(f32::from_ne_bytes(re.try_into().unwrap())),
+                    F32Scalar(f32::from_ne_bytes(im.try_into().unwrap())),
+                ]
+                .serialize(serializer)
+            }
+            ComplexDouble => {
+                let (re, im) = chunk.split_at(mem::size_of::<f64>());
+                [
+                    F64Scalar(f64::from_ne_bytes(re.try_into().unwrap())),
+                    F64Scalar(f64::from_ne_bytes(im.try_into().unwrap())),
+                ]
+                .serialize(serializer)
+            }
+        }
+    }
+
+    /// An untyped leaf or nested array read back from a human-readable format, before
+    /// it's matched up against the tensor's recorded `kind`/`shape`. `String` only ever
+    /// holds a non-finite float sentinel (`"NaN"`/`"inf"`/`"-inf"`) written by
+    /// [`serialize_f32_scalar`]/[`serialize_f64_scalar`].
+    #[derive(Debug, Deserialize)]
+    #[serde(untagged)]
+    enum NestedScalar {
+        Bool(bool),
+        Int(i64),
+        Float(f64),
+        String(String),
+        Seq(Vec<NestedScalar>),
+    }
+
+    /// Walks `value` against `shape`, appending `kind`-typed, host-native-order bytes
+    /// for each leaf into `out`.
+    fn flatten_nested(
+        value: &NestedScalar,
+        shape: &[i64],
+        kind: Kind,
+        out: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        match shape.split_first() {
+            None => write_scalar(value, kind, out),
+            Some((&dim, rest)) => {
+                let items = match value {
+                    NestedScalar::Seq(items) => items,
+                    other => {
+                        return Err(format!(
+                            "expected a nested array matching tensor shape, found {:?}",
+                            other
+                        ))
+                    }
+                };
+                let dim = usize::try_from(dim)
+                    .map_err(|_| "tensor shape has a negative dimension".to_string())?;
+                if items.len() != dim {
+                    return Err(format!(
+                        "expected {} elements along this dimension, found {}",
+                        dim,
+                        items.len()
+                    ));
+                }
+                for item in items {
+                    flatten_nested(item, rest, kind, out)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn write_scalar(value: &NestedScalar, kind: Kind, out: &mut Vec<u8>) -> Result<(), String> {
+        use Kind::*;
+
+        match kind {
+            Uint8 | QUInt8 => out.push(as_i64(value)? as u8),
+            Int8 | QInt8 => out.push(as_i64(value)? as i8 as u8),
+            Int16 => out.extend_from_slice(&(as_i64(value)? as i16).to_ne_bytes()),
+            Int | QInt32 => out.extend_from_slice(&(as_i64(value)? as i32).to_ne_bytes()),
+            Int64 => out.extend_from_slice(&as_i64(value)?.to_ne_bytes()),
+            Half => out.extend_from_slice(&f16::from_f32(as_f64(value)? as f32).to_ne_bytes()),
+            BFloat16 => out.extend_from_slice(&f32_to_bf16(as_f64(value)? as f32).to_ne_bytes()),
+            Float => out.extend_from_slice(&(as_f64(value)? as f32).to_ne_bytes()),
+            Double => out.extend_from_slice(&as_f64(value)?.to_ne_bytes()),
+            Bool => out.push(as_bool(value)? as u8),
+            ComplexHalf => {
+                let (re, im) = as_complex_pair(value)?;
+                out.extend_from_slice(&f16::from_f32(re as f32).to_ne_bytes());
+                out.extend_from_slice(&f16::from_f32(im as f32).to_ne_bytes());
+            }
+            ComplexFloat => {
+                let (re, im) = as_complex_pair(value)?;
+                out.extend_from_slice(&(re as f32).to_ne_bytes());
+                out.extend_from_slice(&(im as f32).to_ne_bytes());
+            }
+            ComplexDouble => {
+                let (re, im) = as_complex_pair(value)?;
+                out.extend_from_slice(&re.to_ne_bytes());
+                out.extend_from_slice(&im.to_ne_bytes());
+            }
+        }
+        Ok(())
+    }
+
+    fn as_i64(value: &NestedScalar) -> Result<i64, String> {
+        match value {
+            NestedScalar::Int(v) => Ok(*v),
+            NestedScalar::Float(v) => Ok(*v as i64),
+            other => Err(format!("expected a number, found {:?}", other)),
+        }
+    }
+
+    fn as_f64(value: &NestedScalar) -> Result<f64, String> {
+        match value {
+            NestedScalar::Int(v) => Ok(*v as f64),
+            NestedScalar::Float(v) => Ok(*v),
+            NestedScalar::String(s) => s.parse::<f64>().map_err(|_| {
+                format!(
+                    "expected a number or a \"NaN\"/\"inf\"/\"-inf\" sentinel, found {:?}",
+                    s
+                )
+            }),
+            other => Err(format!("expected a number, found {:?}", other)),
+        }
+    }
+
+    fn as_bool(value: &NestedScalar) -> Result<bool, String> {
+        match value {
+            NestedScalar::Bool(v) => Ok(*v),
+            other => Err(format!("expected a bool, found {:?}", other)),
+        }
+    }
+
+    fn as_complex_pair(value: &NestedScalar) -> Result<(f64, f64), String> {
+        match value {
+            NestedScalar::Seq(items) if items.len() == 2 => {
+                Ok((as_f64(&items[0])?, as_f64(&items[1])?))
+            }
+            other => Err(format!("expected a [re, im] pair, found {:?}", other)),
+        }
+    }
+
     pub fn serialize<S>(tensor: &Tensor, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -113,43 +546,181 @@ pub mod serde_tensor {
         let shape = tensor.size();
         let kind = tensor.kind();
 
-        let data = {
+        let elem_size = elem_size(kind);
+
+        let mut buffer = {
             let numel = tensor.numel();
-            let elem_size = match kind {
-                Kind::Uint8 => mem::size_of::<u8>(),
-                Kind::Int8 => mem::size_of::<i8>(),
-                Kind::Int16 => mem::size_of::<i16>(),
-                Kind::Int => mem::size_of::<i32>(),
-                Kind::Int64 => mem::size_of::<i64>(),
-                Kind::Half => mem::size_of::<f16>(),
-                Kind::Float => mem::size_of::<f32>(),
-                Kind::Double => mem::size_of::<f64>(),
-                Kind::Bool => mem::size_of::<bool>(),
-                Kind::QInt8 => mem::size_of::<i8>(),
-                Kind::QUInt8 => mem::size_of::<u8>(),
-                Kind::QInt32 => mem::size_of::<i32>(),
-                Kind::BFloat16 => mem::size_of::<f16>(),
-                _ => {
-                    return Err(S::Error::custom(format!(
-                        "tensor with kind {:?} is not supported yet",
-                        kind
-                    )));
-                }
+            let mut buffer = vec![0u8; numel * elem_size];
+            tensor.copy_data_u8(&mut buffer, numel);
+            buffer
+        };
+
+        if serializer.is_human_readable() {
+            let repr = HumanReadableRepr {
+                requires_grad,
+                device,
+                shape: shape.clone(),
+                kind,
+                data: NestedData {
+                    bytes: &buffer,
+                    shape: &shape,
+                    kind,
+                    offset: &Cell::new(0),
+                },
             };
-            let buf_size = numel * elem_size;
-            let mut buffer = vec![0u8; buf_size];
+            repr.serialize(serializer)
+        } else {
+            // Normalize host-native bytes to little-endian for the wire.
+            if cfg!(target_endian = "big") {
+                let unit_size = swap_unit_size(kind);
+                swap_endianness(&mut buffer, unit_size);
+            }
+
+            let repr = TensorRepr {
+                requires_grad,
+                device,
+                shape,
+                kind,
+                endianness: Endianness::Little,
+                data: buffer,
+            };
+            repr.serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Tensor, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_with_limit(deserializer, DeserializeOptions::default())
+    }
+
+    /// Like [`deserialize`], but rejects payloads whose `shape`/`kind` would exceed
+    /// `options`. The check runs once the payload has been parsed, before its `data` is
+    /// flattened into a tensor buffer and before `Tensor::of_data_size` runs; the
+    /// parsing of `data` itself is not bounded. Useful for services that accept tensors
+    /// from untrusted sources.
+    pub fn deserialize_with_limit<'de, D>(
+        deserializer: D,
+        options: DeserializeOptions,
+    ) -> Result<Tensor, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let HumanReadableReprOwned {
+                requires_grad,
+                device,
+                shape,
+                kind,
+                data,
+            } = Deserialize::deserialize(deserializer)?;
+
+            check_limits(&shape, kind, options.max_elements, options.max_bytes).map_err(D::Error::custom)?;
+
+            let mut buffer = Vec::new();
+            flatten_nested(&data, &shape, kind, &mut buffer).map_err(D::Error::custom)?;
+
+            let tensor = Tensor::of_data_size(&buffer, &shape, kind);
+            let tensor = tensor.set_requires_grad(requires_grad);
+            let tensor = tensor.to_device(device);
+            Ok(tensor)
+        } else {
+            let TensorRepr {
+                requires_grad,
+                device,
+                shape,
+                kind,
+                endianness: Endianness::Little,
+                mut data,
+            } = Deserialize::deserialize(deserializer)?;
+
+            check_limits(&shape, kind, options.max_elements, options.max_bytes).map_err(D::Error::custom)?;
+
+            let expected_len = expected_byte_len(&shape, kind).map_err(D::Error::custom)?;
+            if data.len() != expected_len {
+                return Err(D::Error::custom(format!(
+                    "tensor data has {} bytes, expected {} for shape {:?} and kind {:?}",
+                    data.len(),
+                    expected_len,
+                    shape,
+                    kind
+                )));
+            }
+
+            // The wire bytes are little-endian; swap back to host-native before handing
+            // them to libtorch.
+            if cfg!(target_endian = "big") {
+                let unit_size = swap_unit_size(kind);
+                swap_endianness(&mut data, unit_size);
+            }
+
+            let tensor = Tensor::of_data_size(&data, &shape, kind);
+            let tensor = tensor.set_requires_grad(requires_grad);
+            let tensor = tensor.to_device(device);
+
+            Ok(tensor)
+        }
+    }
+}
+
+/// Serializing/Deserializing functions for [Tensor], encoding raw tensor bytes as a
+/// single base64 string on human-readable formats instead of [`serde_tensor`]'s nested
+/// typed-array form.
+///
+/// Prefer this over [`serde_tensor`] when the nested-array form is too large for
+/// human-readable output (e.g. big tensors embedded in JSON logs) and exact
+/// readability of individual elements isn't needed. Binary formats are handled
+/// identically to [`serde_tensor`].
+pub mod serde_tensor_base64 {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Base64Repr {
+        requires_grad: bool,
+        #[serde(with = "serde_device")]
+        device: Device,
+        shape: Vec<i64>,
+        #[serde(with = "serde_kind")]
+        kind: Kind,
+        endianness: Endianness,
+        data: String,
+    }
+
+    pub fn serialize<S>(tensor: &Tensor, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if !serializer.is_human_readable() {
+            return serde_tensor::serialize(tensor, serializer);
+        }
+
+        let device = tensor.device();
+        let requires_grad = tensor.requires_grad();
+        let shape = tensor.size();
+        let kind = tensor.kind();
+        let elem_size = elem_size(kind);
+
+        let mut buffer = {
+            let numel = tensor.numel();
+            let mut buffer = vec![0u8; numel * elem_size];
             tensor.copy_data_u8(&mut buffer, numel);
             buffer
         };
+        // Use the same canonical little-endian wire order as the raw-byte form.
+        if cfg!(target_endian = "big") {
+            let unit_size = swap_unit_size(kind);
+            swap_endianness(&mut buffer, unit_size);
+        }
 
-        let repr = TensorRepr {
+        let repr = Base64Repr {
             requires_grad,
             device,
             shape,
             kind,
-            data,
+            endianness: Endianness::Little,
+            data: base64::encode(&buffer),
         };
-
         repr.serialize(serializer)
     }
 
@@ -157,15 +728,53 @@ pub mod serde_tensor {
     where
         D: Deserializer<'de>,
     {
-        let TensorRepr {
+        deserialize_with_limit(deserializer, DeserializeOptions::default())
+    }
+
+    /// Like [`deserialize`], but rejects payloads whose `shape`/`kind` would exceed
+    /// `options` before the base64-decoded buffer is handed to `Tensor::of_data_size`.
+    /// Useful when accepting big tensors embedded in JSON logs from untrusted sources.
+    pub fn deserialize_with_limit<'de, D>(
+        deserializer: D,
+        options: DeserializeOptions,
+    ) -> Result<Tensor, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            return serde_tensor::deserialize_with_limit(deserializer, options);
+        }
+
+        let Base64Repr {
             requires_grad,
             device,
             shape,
             kind,
+            endianness: Endianness::Little,
             data,
         } = Deserialize::deserialize(deserializer)?;
 
-        let tensor = Tensor::of_data_size(&data, &shape, kind);
+        check_limits(&shape, kind, options.max_elements, options.max_bytes).map_err(D::Error::custom)?;
+
+        let mut bytes = base64::decode(&data).map_err(D::Error::custom)?;
+
+        let expected_len = expected_byte_len(&shape, kind).map_err(D::Error::custom)?;
+        if bytes.len() != expected_len {
+            return Err(D::Error::custom(format!(
+                "tensor data has {} bytes, expected {} for shape {:?} and kind {:?}",
+                bytes.len(),
+                expected_len,
+                shape,
+                kind
+            )));
+        }
+
+        if cfg!(target_endian = "big") {
+            let unit_size = swap_unit_size(kind);
+            swap_endianness(&mut bytes, unit_size);
+        }
+
+        let tensor = Tensor::of_data_size(&bytes, &shape, kind);
         let tensor = tensor.set_requires_grad(requires_grad);
         let tensor = tensor.to_device(device);
 
@@ -549,6 +1158,231 @@ mod tests {
             assert_eq!(orig_tensor, recovered_tensor);
         }
 
+        for _ in 0..100 {
+            let orig = Example(Tensor::randn(
+                &[3, 2, 4],
+                (Kind::ComplexFloat, Device::cuda_if_available()),
+            ));
+            let text = serde_json::to_string(&orig)?;
+            let recovered = serde_json::from_str(&text)?;
+
+            let Example(orig_tensor) = orig;
+            let Example(recovered_tensor) = recovered;
+
+            assert_eq!(orig_tensor.size(), recovered_tensor.size());
+            assert_eq!(orig_tensor.kind(), recovered_tensor.kind());
+            assert_eq!(orig_tensor, recovered_tensor);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn serde_tensor_human_readable_shape_test() -> Result<()> {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Example(#[serde(with = "serde_tensor")] Tensor);
+
+        let tensor = Example(Tensor::of_slice(&[1.0f32, 2.0, 3.0, 4.0]).reshape(&[2, 2]));
+        let value: serde_json::Value = serde_json::from_str(&serde_json::to_string(&tensor)?)?;
+        assert_eq!(
+            value["data"],
+            serde_json::json!([[1.0, 2.0], [3.0, 4.0]])
+        );
+
+        // 0-d (scalar) and empty-dimension tensors are edge cases for the recursion.
+        let scalar = Example(Tensor::of_slice(&[42.0f32]).reshape(&[]));
+        let value: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&scalar)?)?;
+        assert_eq!(value["data"], serde_json::json!(42.0));
+
+        let empty = Example(Tensor::of_slice(&[] as &[f32]).reshape(&[0, 3]));
+        let value: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&empty)?)?;
+        assert_eq!(value["data"], serde_json::json!([]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn serde_tensor_non_finite_test() -> Result<()> {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Example(#[serde(with = "serde_tensor")] Tensor);
+
+        let orig = Example(Tensor::of_slice(&[
+            f32::NAN,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            -1.5,
+        ]));
+        let text = serde_json::to_string(&orig)?;
+
+        // Non-finite values are encoded as string sentinels rather than JSON numbers,
+        // which `serde_json` would otherwise collapse to `null` and fail to deserialize.
+        let value: serde_json::Value = serde_json::from_str(&text)?;
+        assert_eq!(
+            value["data"],
+            serde_json::json!(["NaN", "inf", "-inf", -1.5])
+        );
+
+        let Example(recovered) = serde_json::from_str(&text)?;
+        assert!(recovered.double_value(&[0]).is_nan());
+        assert_eq!(recovered.double_value(&[1]), f64::INFINITY);
+        assert_eq!(recovered.double_value(&[2]), f64::NEG_INFINITY);
+        assert_eq!(recovered.double_value(&[3]), -1.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn serde_tensor_base64_test() -> Result<()> {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Example(#[serde(with = "serde_tensor_base64")] Tensor);
+
+        for _ in 0..100 {
+            let orig = Example(Tensor::randn(
+                &[3, 2, 4],
+                (Kind::Float, Device::cuda_if_available()),
+            ));
+            let text = serde_json::to_string(&orig)?;
+            // `data` is a single base64 string, not a numeric array.
+            let value: serde_json::Value = serde_json::from_str(&text)?;
+            assert!(value["data"].is_string());
+
+            let recovered = serde_json::from_str(&text)?;
+            let Example(orig_tensor) = orig;
+            let Example(recovered_tensor) = recovered;
+
+            assert_eq!(orig_tensor.size(), recovered_tensor.size());
+            assert_eq!(orig_tensor.kind(), recovered_tensor.kind());
+            assert_eq!(orig_tensor, recovered_tensor);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn expected_byte_len_test() -> Result<()> {
+        assert_eq!(expected_byte_len(&[2, 3], Kind::Float), Ok(24));
+        assert_eq!(expected_byte_len(&[2, 3], Kind::ComplexFloat), Ok(48));
+        assert!(expected_byte_len(&[-1, 3], Kind::Float).is_err());
+        assert!(expected_byte_len(&[i64::MAX, 2], Kind::Float).is_err());
+
+        Ok(())
+    }
+
+    // Unlike the round-trip tests above, this calls `swap_endianness` directly so the
+    // byte-swap path is exercised regardless of the host's actual endianness (the
+    // `cfg!(target_endian = "big")` call sites only run it on big-endian hosts).
+    #[test]
+    fn swap_endianness_test() {
+        // Int16: 2-byte units.
+        let mut buffer = vec![0x01, 0x02, 0x03, 0x04];
+        swap_endianness(&mut buffer, swap_unit_size(Kind::Int16));
+        assert_eq!(buffer, vec![0x02, 0x01, 0x04, 0x03]);
+
+        // Int64: a single 8-byte unit.
+        let mut buffer: Vec<u8> = (0..8).collect();
+        swap_endianness(&mut buffer, swap_unit_size(Kind::Int64));
+        assert_eq!(buffer, vec![7, 6, 5, 4, 3, 2, 1, 0]);
+
+        // ComplexFloat: `swap_unit_size` returns one `f32`-sized half (4 bytes) of
+        // `elem_size`'s full 8-byte `[re, im]` pair, so each half swaps independently.
+        let mut buffer: Vec<u8> = (0..8).collect();
+        swap_endianness(&mut buffer, swap_unit_size(Kind::ComplexFloat));
+        assert_eq!(buffer, vec![3, 2, 1, 0, 7, 6, 5, 4]);
+
+        // Uint8: single-byte units never swap.
+        let mut buffer = vec![9, 8, 7];
+        swap_endianness(&mut buffer, swap_unit_size(Kind::Uint8));
+        assert_eq!(buffer, vec![9, 8, 7]);
+    }
+
+    #[test]
+    fn serde_tensor_deserialize_with_limit_test() -> Result<()> {
+        #[derive(Debug, Serialize)]
+        struct Example(#[serde(with = "serde_tensor")] Tensor);
+
+        let tensor = Tensor::randn(&[3, 2, 4], (Kind::Float, Device::cuda_if_available()));
+        let text = serde_json::to_string(&Example(tensor))?;
+
+        let rejected = serde_tensor::deserialize_with_limit(
+            &mut serde_json::Deserializer::from_str(&text),
+            DeserializeOptions {
+                max_elements: Some(10),
+                max_bytes: None,
+            },
+        );
+        assert!(rejected.is_err());
+
+        let accepted = serde_tensor::deserialize_with_limit(
+            &mut serde_json::Deserializer::from_str(&text),
+            DeserializeOptions {
+                max_elements: Some(1024),
+                max_bytes: None,
+            },
+        );
+        assert!(accepted.is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn serde_tensor_deserialize_shape_mismatch_test() -> Result<()> {
+        #[derive(Debug, Deserialize)]
+        struct Example(#[serde(with = "serde_tensor")] Tensor);
+
+        // `shape` claims 4 elements, but `data` only has 3.
+        let text = r#"{"requires_grad":false,"device":"cpu","shape":[2,2],"kind":"float","data":[1.0,2.0,3.0]}"#;
+        let result: Result<Example, _> = serde_json::from_str(text);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn serde_tensor_deserialize_binary_shape_mismatch_test() -> Result<()> {
+        // bincode is not human-readable, so this exercises the raw-byte `TensorRepr`
+        // branch of `serde_tensor::deserialize`, not the nested-array one above.
+        //
+        // Hand-build the `TensorRepr` so the encoded message is internally consistent
+        // (its `data` length prefix matches the bytes actually present) but disagrees
+        // with `shape`/`kind`, so it's `expected_byte_len`'s check that rejects it, not
+        // bincode running out of bytes to read.
+        let repr = TensorRepr {
+            requires_grad: false,
+            device: Device::Cpu,
+            shape: vec![2, 2],
+            kind: Kind::Float,
+            endianness: Endianness::Little,
+            data: vec![0u8; 8], // shape [2, 2] of `Float` needs 16 bytes, not 8.
+        };
+        let bytes = bincode::serialize(&repr)?;
+
+        #[derive(Debug, Deserialize)]
+        struct Example(#[serde(with = "serde_tensor")] Tensor);
+
+        let result: bincode::Result<Example> = bincode::deserialize(&bytes);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn serde_tensor_base64_deserialize_malformed_test() -> Result<()> {
+        #[derive(Debug, Deserialize)]
+        struct Example(#[serde(with = "serde_tensor_base64")] Tensor);
+
+        // Negative dimension: must be rejected before it ever reaches `Tensor::of_data_size`.
+        let negative_shape = r#"{"requires_grad":false,"device":"cpu","shape":[-1,3],"kind":"float","endianness":"little","data":"AAAA"}"#;
+        let result: Result<Example, _> = serde_json::from_str(negative_shape);
+        assert!(result.is_err());
+
+        // `shape`/`kind` claim 2x3 floats (24 bytes), but the base64 `data` decodes to
+        // far fewer bytes than that.
+        let truncated_data = r#"{"requires_grad":false,"device":"cpu","shape":[2,3],"kind":"float","endianness":"little","data":"AAAA"}"#;
+        let result: Result<Example, _> = serde_json::from_str(truncated_data);
+        assert!(result.is_err());
+
         Ok(())
     }
 }